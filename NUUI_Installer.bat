@@ -91,7 +91,7 @@ if "%version%"=="3" (
         set "psCommand=!psCommand! Add-Content -Path $settingsFile -Value 'show_config_files = false';"
         set "psCommand=!psCommand! Add-Content -Path $settingsFile -Value 'show_clock = true';"
         set "psCommand=!psCommand! Add-Content -Path $settingsFile -Value 'show_size = false';"
-        set "psCommand=!psCommand! Add-Content -Path $settingsFile -Value 'options = [\"ping_tool\", \"port_scan\", \"micro_macro\", \"macro\", \"tetris\", \"game_of_life\", \"custom\\cheat_eng\", \"custom\\gen_ip\", \"custom\\mouse_crds\", \"custom\\whos_on\", \"custom\\win_cleanup\", \"custom\\x_counter\", \"custom\\yt_dwn\", \"custom\\yt_dwn_dir\\yt_dwnlds\"]';"
+        set "psCommand=!psCommand! Add-Content -Path $settingsFile -Value 'options = [\"ping_tool\", \"http_check\", \"traceroute\", \"dns_lookup\", \"port_scan\", \"subnet_calc\", \"wake_on_lan\", \"quick_download\", \"micro_macro\", \"macro\", \"tetris\", \"game_of_life\", \"custom\\cheat_eng\", \"custom\\gen_ip\", \"custom\\mouse_crds\", \"custom\\win_cleanup\", \"custom\\x_counter\", \"custom\\yt_dwn\", \"custom\\yt_dwn_dir\\yt_dwnlds\"]';"
 
         set "psCommand=!psCommand! $customDir = [System.IO.Path]::Combine('%installPath%', 'custom');"
         set "psCommand=!psCommand! if (-not (Test-Path $customDir)) { New-Item -ItemType Directory -Path $customDir | Out-Null };"